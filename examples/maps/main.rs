@@ -28,6 +28,7 @@
     callback link-clicked();
     min-height: 500px;
     min-width: 500px;
+    forward-focus: key-handler;
 
     out property <length> visible_width: fli.width;
     out property <length> visible_height: fli.height;
@@ -43,36 +44,76 @@
         fli.viewport-height = height;
     }
 
-    VerticalLayout {
-        fli := Flickable {
-            for t in tiles: Image {
-                x: t.x;
-                y: t.y;
-                source: t.tile;
-            }
-            flicked => {
-                root.flicked(fli.viewport-x, fli.viewport-y);
+    // Pan by the given amount in screen space, staying inside the world
+    function pan-by(dx: length, dy: length) {
+        fli.viewport-x = min(max(fli.viewport-x - dx, fli.width - fli.viewport-width), 0);
+        fli.viewport-y = min(max(fli.viewport-y - dy, fli.height - fli.viewport-height), 0);
+        root.flicked(fli.viewport-x, fli.viewport-y);
+    }
+
+    key-handler := FocusScope {
+        key-pressed(event) => {
+            if event.text == Key.LeftArrow {
+                root.pan-by(-64px, 0);
+                return accept;
+            } else if event.text == Key.RightArrow {
+                root.pan-by(64px, 0);
+                return accept;
+            } else if event.text == Key.UpArrow {
+                root.pan-by(0, -64px);
+                return accept;
+            } else if event.text == Key.DownArrow {
+                root.pan-by(0, 64px);
+                return accept;
+            } else if event.text == "+" || event.text == "=" {
+                root.zoom-in(fli.width / 2, fli.height / 2);
+                return accept;
+            } else if event.text == "-" {
+                root.zoom-out(fli.width / 2, fli.height / 2);
+                return accept;
             }
-            TouchArea {
-                scroll-event(e) => {
-                    if e.delta-y > 0 {
+            return reject;
+        }
+
+        VerticalLayout {
+            fli := Flickable {
+                for t in tiles: Image {
+                    x: t.x;
+                    y: t.y;
+                    source: t.tile;
+                }
+                flicked => {
+                    root.flicked(fli.viewport-x, fli.viewport-y);
+                }
+                TouchArea {
+                    scroll-event(e) => {
+                        if e.delta-y > 0 {
+                            root.zoom-in(self.mouse-x + fli.viewport-x, self.mouse-y + fli.viewport-y);
+                            return accept;
+                        } else if e.delta-y < 0 {
+                            root.zoom-out(self.mouse-x + fli.viewport-x, self.mouse-y + fli.viewport-y);
+                            return accept;
+                        }
+                        return reject;
+                    }
+                    pointer-event(e) => {
+                        if e.kind == PointerEventKind.down {
+                            key-handler.focus();
+                        }
+                    }
+                    double-clicked => {
                         root.zoom-in(self.mouse-x + fli.viewport-x, self.mouse-y + fli.viewport-y);
-                        return accept;
-                    } else if e.delta-y < 0 {
-                        root.zoom-out(self.mouse-x + fli.viewport-x, self.mouse-y + fli.viewport-y);
-                        return accept;
                     }
-                    return reject;
                 }
             }
-        }
 
-        HorizontalLayout {
-            sli := Slider {
-                minimum: 1;
-                maximum: 19;
-                released => {
-                    zoom-changed(self.value);
+            HorizontalLayout {
+                sli := Slider {
+                    minimum: 1;
+                    maximum: 19;
+                    released => {
+                        zoom-changed(self.value);
+                    }
                 }
             }
         }